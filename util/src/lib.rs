@@ -7,7 +7,9 @@ extern crate alloc;
 use alloc::vec::Vec;
 use core::{
     hint::unreachable_unchecked,
+    mem::MaybeUninit,
     ops::{BitAnd, Deref, Shl, Shr, Sub},
+    ptr,
 };
 
 pub mod array_serialization;
@@ -20,6 +22,9 @@ pub const fn ceil_div_usize(a: usize, b: usize) -> usize {
 }
 
 /// Computes `ceil(log_2(n))`.
+///
+/// See [`IntLog`] for a width-generic version of this, and for checked
+/// variants that don't panic on bad input.
 #[must_use]
 pub const fn log2_ceil_usize(n: usize) -> usize {
     (usize::BITS - n.saturating_sub(1).leading_zeros()) as usize
@@ -37,11 +42,102 @@ pub fn log2_ceil_u64(n: u64) -> u64 {
 #[must_use]
 #[inline]
 pub fn log2_strict_usize(n: usize) -> usize {
-    let res = n.trailing_zeros();
-    assert_eq!(n.wrapping_shr(res), 1, "Not a power of two: {n}");
-    res as usize
+    n.log2_strict()
+}
+
+/// A trait for computing integer logarithms, width-generic over `usize`,
+/// `u32` and `u64`.
+///
+/// This unifies the `log2_ceil_*`/`log2_strict_*` family of free functions,
+/// which used to be duplicated per integer width and mixed panicking and
+/// non-panicking conventions. Callers can now write width-generic code and
+/// pick explicitly between the panicking strict variant
+/// ([`log2_strict`](Self::log2_strict)) and a checked one
+/// ([`checked_log2_strict`](Self::checked_log2_strict)).
+pub trait IntLog {
+    /// Computes `floor(log_2(self))`.
+    ///
+    /// # Panics
+    /// Panics if `self == 0`.
+    fn log2_floor(self) -> usize;
+
+    /// Computes `ceil(log_2(self))`.
+    fn log2_ceil(self) -> usize;
+
+    /// Computes `log_2(self)`, returning `None` if `self` is not a power of two.
+    fn checked_log2_strict(self) -> Option<usize>;
+
+    /// Computes `log_2(self)`.
+    ///
+    /// # Panics
+    /// Panics if `self` is not a power of two.
+    fn log2_strict(self) -> usize;
+
+    /// Computes `floor(log_base(self))`, mirroring [`u32::checked_ilog`].
+    ///
+    /// Returns `None` if `self == 0`.
+    fn checked_ilog(self, base: Self) -> Option<usize>;
+
+    /// Computes `ceil(log_base(self))`.
+    ///
+    /// # Panics
+    /// Panics if `self == 0`.
+    fn log_ceil(self, base: Self) -> usize;
 }
 
+macro_rules! impl_int_log {
+    ($($t:ty),* $(,)?) => {$(
+        impl IntLog for $t {
+            #[inline]
+            fn log2_floor(self) -> usize {
+                assert_ne!(self, 0, "self must be nonzero");
+                (<$t>::BITS - 1 - self.leading_zeros()) as usize
+            }
+
+            #[inline]
+            fn log2_ceil(self) -> usize {
+                (<$t>::BITS - self.saturating_sub(1).leading_zeros()) as usize
+            }
+
+            #[inline]
+            fn checked_log2_strict(self) -> Option<usize> {
+                let res = self.trailing_zeros();
+                if self != 0 && self.wrapping_shr(res) == 1 {
+                    Some(res as usize)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn log2_strict(self) -> usize {
+                self.checked_log2_strict()
+                    .unwrap_or_else(|| panic!("Not a power of two: {self}"))
+            }
+
+            #[inline]
+            fn checked_ilog(self, base: Self) -> Option<usize> {
+                <$t>::checked_ilog(self, base).map(|log| log as usize)
+            }
+
+            #[inline]
+            fn log_ceil(self, base: Self) -> usize {
+                // `self.checked_ilog(base)` would resolve to the inherent
+                // `checked_ilog` (returning `Option<u32>`) rather than this
+                // trait's method of the same name, so call it via UFCS.
+                let floor = <Self as IntLog>::checked_ilog(self, base).expect("self must be nonzero");
+                if base.pow(floor as u32) == self {
+                    floor
+                } else {
+                    floor + 1
+                }
+            }
+        }
+    )*};
+}
+
+impl_int_log!(usize, u32, u64);
+
 /// Returns `[0, ..., N - 1]`.
 #[must_use]
 pub const fn indices_arr<const N: usize>() -> [usize; N] {
@@ -70,6 +166,17 @@ pub const fn reverse_bits_len(x: usize, bit_len: usize) -> usize {
         .0
 }
 
+/// Number of bits `b` used to block the bit-reversal permutation below.
+/// Chosen so that the two `2^(2 * BIT_REV_BLOCK_BITS)`-element scratch buffers
+/// it needs comfortably fit in L1/L2 cache.
+const BIT_REV_BLOCK_BITS: usize = 8;
+
+/// Applies the bit-reversal permutation to `vals` in place: swaps `vals[i]`
+/// with `vals[reverse_bits_len(i, log2(vals.len()))]` for every `i`.
+///
+/// For slices large enough to benefit, this uses a cache-oblivious blocked
+/// algorithm (COBRA) instead of the naive `O(n)`-cache-misses loop; see
+/// [`reverse_slice_index_bits_cobra`].
 pub fn reverse_slice_index_bits<F>(vals: &mut [F]) {
     let n = vals.len();
     if n == 0 {
@@ -77,7 +184,17 @@ pub fn reverse_slice_index_bits<F>(vals: &mut [F]) {
     }
     let log_n = log2_strict_usize(n);
 
-    for i in 0..n {
+    if log_n < 2 * BIT_REV_BLOCK_BITS {
+        reverse_slice_index_bits_naive(vals, log_n);
+    } else {
+        reverse_slice_index_bits_cobra(vals, log_n);
+    }
+}
+
+/// The direct implementation: one essentially-random swap per element, so
+/// one cache miss per element on large slices.
+fn reverse_slice_index_bits_naive<F>(vals: &mut [F], log_n: usize) {
+    for i in 0..vals.len() {
         let j = reverse_bits_len(i, log_n);
         if i < j {
             vals.swap(i, j);
@@ -85,6 +202,109 @@ pub fn reverse_slice_index_bits<F>(vals: &mut [F]) {
     }
 }
 
+/// Cache-oblivious blocked bit-reversal permutation (COBRA).
+///
+/// Write each index as `i = (a << (m + b)) | (mid << b) | c`, where `a` and
+/// `c` are `b`-bit fields (`b` = [`BIT_REV_BLOCK_BITS`]) and `mid` is the
+/// `m = log_n - 2b` bits in between. Its bit-reversal is
+/// `(rev_b(c) << (m + b)) | (rev_m(mid) << b) | rev_b(a)`: the top and
+/// bottom `b`-bit fields swap (each reversed) while the middle field is only
+/// reversed in place. So for a fixed `mid`, the whole `2^b x 2^b` tile of
+/// `(a, c)` pairs maps into the tile for `rev_m(mid)`.
+///
+/// For each such pair of tiles we gather both into small local buffers with
+/// contiguous reads (sequential in `c`), do the index-scrambling transpose
+/// entirely within those cache-resident buffers using a precomputed `b`-bit
+/// reversal table, then scatter the results back with contiguous writes.
+/// This turns the naive version's `O(n)` essentially-random accesses into
+/// `O(n / 2^b)` cache misses.
+///
+/// # Panics
+/// Panics if `log_n < 2 * BIT_REV_BLOCK_BITS`; callers should use
+/// [`reverse_slice_index_bits_naive`] below that threshold.
+fn reverse_slice_index_bits_cobra<F>(vals: &mut [F], log_n: usize) {
+    const B: usize = BIT_REV_BLOCK_BITS;
+    assert!(log_n >= 2 * B);
+    let m = log_n - 2 * B;
+    let block_len = 1usize << B;
+    let tile_len = block_len * block_len;
+    let mid_len = 1usize << m;
+
+    // `rev_b_table[x] == reverse_bits_len(x, B)`, shared by every tile.
+    let rev_b_table: Vec<usize> = (0..block_len).map(|x| reverse_bits_len(x, B)).collect();
+
+    let index = |mid: usize, a: usize, c: usize| (a << (m + B)) | (mid << B) | c;
+
+    // A raw pointer, rather than `vals` itself, is captured below so that
+    // `gather` and `scatter` can be called in any order/combination without
+    // the borrow checker seeing them as aliasing mutable borrows of `vals`;
+    // the algorithm's own structure (see the loop below) is what actually
+    // guarantees there is no aliasing.
+    let data_ptr = vals.as_mut_ptr();
+
+    // Gathers the `2^b x 2^b` tile at middle value `mid` into `buf`, reading
+    // each of the tile's `2^b` rows contiguously.
+    let gather = |buf: &mut [MaybeUninit<F>], mid: usize| {
+        for a in 0..block_len {
+            for c in 0..block_len {
+                // SAFETY: `index(mid, a, c) < vals.len()` since `mid <
+                // mid_len`, `a, c < block_len`, and `index`'s three fields
+                // exactly tile `0..log_n` bits. Each slot is read here at
+                // most once before `scatter` below overwrites it, so this
+                // is a genuine move, not a duplication.
+                unsafe {
+                    buf[a * block_len + c]
+                        .as_mut_ptr()
+                        .write(ptr::read(data_ptr.add(index(mid, a, c))));
+                }
+            }
+        }
+    };
+
+    // Scatters `buf`, transposed-with-reversal, into the tile at `dst_mid`:
+    // `vals[index(dst_mid, a, c)] = buf[rev_b(c)][rev_b(a)]`.
+    let scatter = |buf: &[MaybeUninit<F>], dst_mid: usize| {
+        for a in 0..block_len {
+            for c in 0..block_len {
+                let src = rev_b_table[c] * block_len + rev_b_table[a];
+                // SAFETY: each `buf` slot was filled exactly once by
+                // `gather` and is read exactly once here.
+                unsafe {
+                    let val = buf[src].as_ptr().read();
+                    data_ptr.add(index(dst_mid, a, c)).write(val);
+                }
+            }
+        }
+    };
+
+    let mut buf_a: Vec<MaybeUninit<F>> = (0..tile_len).map(|_| MaybeUninit::uninit()).collect();
+    let mut buf_b: Vec<MaybeUninit<F>> = (0..tile_len).map(|_| MaybeUninit::uninit()).collect();
+
+    for mid in 0..mid_len {
+        let mid_rev = reverse_bits_len(mid, m);
+        if mid > mid_rev {
+            // The pair `{mid, mid_rev}` was already handled as `mid_rev`.
+            continue;
+        }
+
+        gather(&mut buf_a, mid);
+        if mid == mid_rev {
+            // Self-paired middle value: the tile maps onto itself.
+            scatter(&buf_a, mid);
+        } else {
+            gather(&mut buf_b, mid_rev);
+            scatter(&buf_a, mid_rev);
+            scatter(&buf_b, mid);
+        }
+    }
+}
+
+/// Computes a mask of the bottom `n_bits` bits, i.e. `2^n_bits - 1`.
+///
+/// # Panics / UB
+/// Like the bare `1 << n_bits`, this overflows (panicking in debug builds,
+/// silently wrapping in release) if `n_bits` equals the bit width of `T`.
+/// Use [`checked_bitmask`] if `n_bits` isn't known to be in range.
 pub fn bitmask<T>(n_bits: T) -> T
 where
     T: Copy + From<bool> + Shl<T, Output = T> + Sub<T, Output = T>,
@@ -93,7 +313,11 @@ where
     (one << n_bits) - one
 }
 
-/// (x >> n, x & mask(n))
+/// `(x >> n, x & mask(n))`.
+///
+/// # Panics / UB
+/// Like [`bitmask`], this overflows if `n` equals `usize::BITS`. Use
+/// [`checked_split_bits`] if `n` isn't known to be in range.
 pub fn split_bits<T>(x: T, n: usize) -> (T, T)
 where
     T: Copy + Shr<usize, Output = T> + BitAnd<usize, Output = T>,
@@ -101,6 +325,52 @@ where
     (x >> n, x & ((1 << n) - 1))
 }
 
+/// Non-panicking, UB-free counterpart to [`bitmask`].
+///
+/// Returns `None` if `n_bits > usize::BITS`; unlike the bare
+/// `(1 << n_bits) - 1`, `n_bits == usize::BITS` is handled correctly,
+/// yielding an all-ones mask instead of overflowing.
+#[must_use]
+pub const fn checked_bitmask(n_bits: u32) -> Option<usize> {
+    if n_bits > usize::BITS {
+        None
+    } else if n_bits == usize::BITS {
+        Some(usize::MAX)
+    } else {
+        // `n_bits < usize::BITS` here, so the shift (and the following
+        // subtraction, since `1 << n_bits >= 1`) cannot overflow.
+        Some((1usize << n_bits) - 1)
+    }
+}
+
+/// Non-panicking, UB-free counterpart to [`split_bits`].
+///
+/// Returns `None` if `n > usize::BITS`; unlike the bare `x >> n`,
+/// `n == usize::BITS` is handled correctly, yielding `(0, x)`.
+#[must_use]
+pub const fn checked_split_bits(x: usize, n: u32) -> Option<(usize, usize)> {
+    let Some(mask) = checked_bitmask(n) else {
+        return None;
+    };
+    let hi = if n == usize::BITS {
+        0
+    } else {
+        // `overflowing_shr` is the same trick `reverse_bits_len` uses: it
+        // keeps this defined even if this function is ever generalized to
+        // shift counts computed at runtime from untrusted input.
+        x.overflowing_shr(n).0
+    };
+    Some((hi, x & mask))
+}
+
+/// Non-panicking counterpart to [`log2_strict_usize`].
+///
+/// Returns `None` if `n` is not a power of two, instead of panicking.
+#[must_use]
+pub fn checked_log2_strict(n: usize) -> Option<usize> {
+    n.checked_log2_strict()
+}
+
 #[inline(always)]
 pub fn assume(p: bool) {
     debug_assert!(p);
@@ -172,28 +442,256 @@ pub trait SliceExt {
 
 impl<T, S: Deref<Target = [T]>> SliceExt for S {
     fn log_len(&self) -> Option<usize> {
-        let res = self.len().trailing_zeros();
-        if self.len().wrapping_shr(res) == 1 {
-            Some(res as usize)
-        } else {
-            None
-        }
+        self.len().checked_log2_strict()
     }
     fn log_strict_len(&self) -> usize {
-        log2_strict_usize(self.len())
+        self.len().log2_strict()
+    }
+}
+
+/// Transposes the `n x n` row-major matrix in `data` in place, reusing its
+/// backing allocation rather than allocating a second buffer.
+///
+/// # Panics
+/// Panics if `data.len() != n * n`.
+pub fn transpose_square_in_place<T>(data: &mut [T], n: usize) {
+    assert_eq!(data.len(), n * n, "data.len() must equal n * n");
+
+    // Follow each cycle of the transpose permutation `i -> (i % n) * n +
+    // i / n`, marking indices off as we place them so each cycle is only
+    // walked once. The permutation is its own inverse, so every cycle has
+    // length 1 (diagonal entries) or 2, but walking it generically keeps
+    // this independent of that fact.
+    let mut visited = alloc::vec![false; data.len()];
+    for start in 0..data.len() {
+        if visited[start] {
+            continue;
+        }
+        let mut i = start;
+        loop {
+            visited[i] = true;
+            let next = (i % n) * n + i / n;
+            if next == start || visited[next] {
+                break;
+            }
+            data.swap(i, next);
+            i = next;
+        }
+    }
+}
+
+/// Transposes the `rows x cols` row-major matrix in `data` into `out`,
+/// viewed as a `cols x rows` row-major matrix.
+///
+/// # Panics
+/// Panics unless `data.len() == out.len() == rows * cols`.
+pub fn transpose_rect<T: Clone>(data: &[T], out: &mut [T], rows: usize, cols: usize) {
+    assert_eq!(data.len(), rows * cols, "data.len() must equal rows * cols");
+    assert_eq!(out.len(), rows * cols, "out.len() must equal rows * cols");
+
+    for r in 0..rows {
+        for c in 0..cols {
+            out[c * rows + r] = data[r * cols + c].clone();
+        }
     }
 }
 
+/// Transposes `v`, treated as a `v.len() x v[0].len()` row-major matrix.
+///
+/// Flattens `v` into a single buffer instead of allocating one `Vec` per
+/// output row up front. Square inputs are transposed with
+/// [`transpose_square_in_place`]; other shapes are moved element by element
+/// into a second buffer (without going through [`transpose_rect`], so no
+/// `T: Clone` bound is needed). The result has `v[0].len()` rows of
+/// `v.len()` elements each.
+///
+/// # Panics
+/// Panics if `v` is empty or its rows don't all have the same length.
 pub fn transpose_vec<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
     assert!(!v.is_empty());
-    let len = v[0].len();
-    let mut iters: Vec<_> = v.into_iter().map(|n| n.into_iter()).collect();
-    (0..len)
-        .map(|_| {
-            iters
-                .iter_mut()
-                .map(|n| n.next().unwrap())
-                .collect::<Vec<T>>()
-        })
-        .collect()
+    let rows = v.len();
+    let cols = v[0].len();
+
+    assert!(
+        v.iter().all(|row| row.len() == cols),
+        "all rows must have the same length"
+    );
+    let mut flat: Vec<T> = v.into_iter().flatten().collect();
+
+    if rows == cols {
+        transpose_square_in_place(&mut flat, rows);
+    } else {
+        // Reinterpret `flat` (currently `rows x cols`) as `cols x rows` by
+        // moving each element to its transposed index, rather than cloning
+        // into a scratch buffer via `transpose_rect`.
+        let mut transposed: Vec<MaybeUninit<T>> =
+            (0..flat.len()).map(|_| MaybeUninit::uninit()).collect();
+        for (i, x) in flat.into_iter().enumerate() {
+            let (r, c) = (i / cols, i % cols);
+            transposed[c * rows + r].write(x);
+        }
+        // SAFETY: every slot was written exactly once above, since `(r, c)`
+        // ranges bijectively over `0..rows * 0..cols` as `i` does.
+        flat = transposed
+            .into_iter()
+            .map(|x| unsafe { x.assume_init() })
+            .collect();
+    }
+
+    // Moves elements out of `flat` rather than cloning them, so `T: Clone`
+    // isn't required here either.
+    let mut it = flat.into_iter();
+    (0..cols).map(|_| it.by_ref().take(rows).collect()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log2_floor_matches_expected() {
+        assert_eq!(1usize.log2_floor(), 0);
+        assert_eq!(2usize.log2_floor(), 1);
+        assert_eq!(3usize.log2_floor(), 1);
+        assert_eq!(4usize.log2_floor(), 2);
+        assert_eq!((u32::MAX as usize).log2_floor(), 31);
+    }
+
+    #[test]
+    #[should_panic]
+    fn log2_floor_panics_on_zero() {
+        0usize.log2_floor();
+    }
+
+    #[test]
+    fn log2_ceil_matches_expected() {
+        assert_eq!(0usize.log2_ceil(), 0);
+        assert_eq!(1usize.log2_ceil(), 0);
+        assert_eq!(2usize.log2_ceil(), 1);
+        assert_eq!(3usize.log2_ceil(), 2);
+        assert_eq!(4usize.log2_ceil(), 2);
+    }
+
+    #[test]
+    fn checked_ilog_matches_expected() {
+        // Called via UFCS: method syntax would pick the inherent
+        // `usize::checked_ilog` instead of `IntLog::checked_ilog`.
+        assert_eq!(<usize as IntLog>::checked_ilog(0, 2), None);
+        assert_eq!(<usize as IntLog>::checked_ilog(1, 2), Some(0));
+        assert_eq!(<usize as IntLog>::checked_ilog(8, 2), Some(3));
+        assert_eq!(<usize as IntLog>::checked_ilog(10, 2), Some(3));
+    }
+
+    #[test]
+    fn log_ceil_matches_expected() {
+        assert_eq!(1usize.log_ceil(2), 0);
+        assert_eq!(2usize.log_ceil(2), 1);
+        assert_eq!(8usize.log_ceil(2), 3);
+        assert_eq!(9usize.log_ceil(2), 4);
+        assert_eq!(1usize.log_ceil(3), 0);
+        assert_eq!(27usize.log_ceil(3), 3);
+        assert_eq!(28usize.log_ceil(3), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn log_ceil_panics_on_zero() {
+        0usize.log_ceil(2);
+    }
+
+    fn naive_bit_reversal(vals: &[usize]) -> Vec<usize> {
+        let n = vals.len();
+        let log_n = log2_strict_usize(n);
+        (0..n).map(|i| vals[reverse_bits_len(i, log_n)]).collect()
+    }
+
+    #[test]
+    fn reverse_slice_index_bits_matches_naive_around_cobra_threshold() {
+        // `BIT_REV_BLOCK_BITS == 8`, so COBRA kicks in at `log_n == 16`;
+        // cover both sides of that threshold, plus tiny/degenerate sizes.
+        for log_n in [0, 1, 2, 5, 15, 16, 17, 18] {
+            let n = 1usize << log_n;
+            let input: Vec<usize> = (0..n).collect();
+            let expected = naive_bit_reversal(&input);
+
+            let mut actual = input.clone();
+            reverse_slice_index_bits(&mut actual);
+
+            assert_eq!(actual, expected, "mismatch at log_n = {log_n}");
+        }
+    }
+
+    fn naive_transpose(rows: usize, cols: usize, data: &[i32]) -> Vec<i32> {
+        let mut out = alloc::vec![0; data.len()];
+        for r in 0..rows {
+            for c in 0..cols {
+                out[c * rows + r] = data[r * cols + c];
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn transpose_square_in_place_matches_naive() {
+        for n in [0, 1, 2, 3, 4, 7, 8] {
+            let data: Vec<i32> = (0..(n * n) as i32).collect();
+            let mut actual = data.clone();
+            transpose_square_in_place(&mut actual, n);
+            assert_eq!(actual, naive_transpose(n, n, &data), "mismatch at n = {n}");
+        }
+    }
+
+    #[test]
+    fn transpose_rect_matches_naive() {
+        for (rows, cols) in [(1, 1), (1, 5), (5, 1), (2, 3), (3, 2), (4, 7)] {
+            let data: Vec<i32> = (0..(rows * cols) as i32).collect();
+            let mut out = alloc::vec![0; data.len()];
+            transpose_rect(&data, &mut out, rows, cols);
+            assert_eq!(out, naive_transpose(rows, cols, &data), "mismatch at {rows}x{cols}");
+        }
+    }
+
+    #[test]
+    fn transpose_vec_matches_naive() {
+        for (rows, cols) in [(1, 1), (1, 5), (5, 1), (2, 3), (3, 2), (4, 4)] {
+            let v: Vec<Vec<i32>> = (0..rows)
+                .map(|r| (0..cols).map(|c| (r * cols + c) as i32).collect())
+                .collect();
+            let flat: Vec<i32> = v.iter().flatten().copied().collect();
+
+            let actual = transpose_vec(v);
+            let actual_flat: Vec<i32> = actual.into_iter().flatten().collect();
+
+            assert_eq!(
+                actual_flat,
+                naive_transpose(rows, cols, &flat),
+                "mismatch at {rows}x{cols}"
+            );
+        }
+    }
+
+    #[test]
+    fn checked_bitmask_edge_cases() {
+        assert_eq!(checked_bitmask(0), Some(0));
+        assert_eq!(checked_bitmask(3), Some(0b111));
+        assert_eq!(checked_bitmask(usize::BITS - 1), Some(usize::MAX >> 1));
+        assert_eq!(checked_bitmask(usize::BITS), Some(usize::MAX));
+        assert_eq!(checked_bitmask(usize::BITS + 1), None);
+    }
+
+    #[test]
+    fn checked_split_bits_edge_cases() {
+        assert_eq!(checked_split_bits(0b1011, 0), Some((0b1011, 0)));
+        assert_eq!(checked_split_bits(0b1011, 2), Some((0b10, 0b11)));
+        assert_eq!(checked_split_bits(usize::MAX, usize::BITS), Some((0, usize::MAX)));
+        assert_eq!(checked_split_bits(usize::MAX, usize::BITS + 1), None);
+    }
+
+    #[test]
+    fn checked_log2_strict_edge_cases() {
+        assert_eq!(checked_log2_strict(0), None);
+        assert_eq!(checked_log2_strict(1), Some(0));
+        assert_eq!(checked_log2_strict(3), None);
+        assert_eq!(checked_log2_strict(1 << 10), Some(10));
+    }
 }